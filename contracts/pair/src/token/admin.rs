@@ -0,0 +1,23 @@
+use crate::token::storage_types::DataKey;
+use soroban_sdk::{Address, Env};
+
+pub fn has_administrator(e: &Env) -> bool {
+    let key = DataKey::Admin;
+    e.storage().instance().has(&key)
+}
+
+pub fn read_administrator(e: &Env) -> Address {
+    let key = DataKey::Admin;
+    e.storage().instance().get(&key).unwrap()
+}
+
+pub fn write_administrator(e: &Env, id: &Address) {
+    let key = DataKey::Admin;
+    e.storage().instance().set(&key, id);
+}
+
+pub fn check_admin(e: &Env, admin: &Address) {
+    if admin != &read_administrator(e) {
+        panic!("not authorized by admin")
+    }
+}
@@ -0,0 +1,81 @@
+use crate::token::storage_types::{
+    AllowanceDataKey, AllowanceValue, DataKey, ALLOWANCE_BUMP_AMOUNT, ALLOWANCE_LIFETIME_THRESHOLD,
+};
+use soroban_sdk::{Address, Env};
+
+pub fn read_allowance(e: &Env, from: &Address, spender: &Address) -> AllowanceValue {
+    let key = DataKey::Allowance(AllowanceDataKey {
+        from: from.clone(),
+        spender: spender.clone(),
+    });
+    if let Some(allowance) = e.storage().temporary().get::<DataKey, AllowanceValue>(&key) {
+        let allowance = if allowance.expiration_ledger < e.ledger().sequence() {
+            AllowanceValue {
+                amount: 0,
+                expiration_ledger: allowance.expiration_ledger,
+            }
+        } else {
+            allowance
+        };
+
+        if allowance.amount > 0 {
+            e.storage().temporary().extend_ttl(
+                &key,
+                ALLOWANCE_LIFETIME_THRESHOLD,
+                ALLOWANCE_BUMP_AMOUNT,
+            );
+        }
+
+        allowance
+    } else {
+        AllowanceValue {
+            amount: 0,
+            expiration_ledger: 0,
+        }
+    }
+}
+
+pub fn write_allowance(
+    e: &Env,
+    from: &Address,
+    spender: &Address,
+    amount: i128,
+    expiration_ledger: u32,
+) {
+    let allowance = AllowanceValue {
+        amount,
+        expiration_ledger,
+    };
+
+    if amount > 0 && expiration_ledger < e.ledger().sequence() {
+        panic!("expiration_ledger is less than ledger sequence")
+    }
+
+    let key = DataKey::Allowance(AllowanceDataKey {
+        from: from.clone(),
+        spender: spender.clone(),
+    });
+    e.storage().temporary().set(&key, &allowance);
+
+    if amount > 0 {
+        let live_for = expiration_ledger.saturating_sub(e.ledger().sequence());
+
+        e.storage().temporary().extend_ttl(&key, live_for, live_for);
+    }
+}
+
+pub fn spend_allowance(e: &Env, from: &Address, spender: &Address, amount: i128) {
+    let allowance = read_allowance(e, from, spender);
+    if allowance.amount < amount {
+        panic!("insufficient allowance");
+    }
+    if amount > 0 {
+        write_allowance(
+            e,
+            from,
+            spender,
+            allowance.amount - amount,
+            allowance.expiration_ledger,
+        );
+    }
+}
@@ -8,16 +8,17 @@ use crate::token::event;
 use crate::token::metadata::{
     read_decimal, read_name, read_symbol, write_decimal, write_name, write_symbol,
 };
-use soroban_sdk::{contract, contractimpl, Address, Bytes, Env};
+use crate::token::receiver::TokenReceiverClient;
+use crate::token::storage_types::{INSTANCE_BUMP_AMOUNT, INSTANCE_LIFETIME_THRESHOLD};
+use crate::token::supply::{decrement_supply, increment_supply, read_supply};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, Env, IntoVal, String};
 
 pub trait TokenTrait {
-    fn initialize(e: Env, admin: Address, decimal: u32, name: Bytes, symbol: Bytes);
+    fn initialize(e: Env, admin: Address, decimal: u32, name: String, symbol: String);
 
     fn allowance(e: Env, from: Address, spender: Address) -> i128;
 
-    fn incr_allow(e: Env, from: Address, spender: Address, amount: i128);
-
-    fn decr_allow(e: Env, from: Address, spender: Address, amount: i128);
+    fn approve(e: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32);
 
     fn balance(e: Env, id: Address) -> i128;
 
@@ -29,6 +30,12 @@ pub trait TokenTrait {
 
     fn transfer_from(e: Env, spender: Address, from: Address, to: Address, amount: i128);
 
+    /// Transfers `amount` to `to` and, within the same invocation, calls
+    /// `to`'s `on_token_received(from, amount, data)`. If the receiver traps
+    /// or returns a nonzero refund, that much of the transfer is reversed
+    /// before the call returns.
+    fn transfer_and_call(e: Env, from: Address, to: Address, amount: i128, data: Bytes);
+
     fn burn(e: Env, from: Address, amount: i128);
 
     fn burn_from(e: Env, spender: Address, from: Address, amount: i128);
@@ -43,9 +50,13 @@ pub trait TokenTrait {
 
     fn decimals(e: Env) -> u32;
 
-    fn name(e: Env) -> Bytes;
+    fn name(e: Env) -> String;
 
-    fn symbol(e: Env) -> Bytes;
+    fn symbol(e: Env) -> String;
+
+    /// Returns the total amount of tokens currently in circulation, i.e. the
+    /// amount minted minus the amount burned or clawed back.
+    fn total_supply(e: Env) -> i128;
 }
 
 fn check_nonnegative_amount(amount: i128) {
@@ -54,12 +65,24 @@ fn check_nonnegative_amount(amount: i128) {
     }
 }
 
+/// Keeps the contract's own instance storage (admin, metadata, total
+/// supply) alive. Called on every entry point, mirroring the Stellar Asset
+/// Contract, so the instance doesn't archive out from under balance and
+/// allowance entries whose own TTLs are bumped on access.
+fn extend_instance_ttl(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
 #[contract]
 pub struct Token;
 
 #[contractimpl]
 impl TokenTrait for Token {
-    fn initialize(e: Env, admin: Address, decimal: u32, name: Bytes, symbol: Bytes) {
+    fn initialize(e: Env, admin: Address, decimal: u32, name: String, symbol: String) {
+        extend_instance_ttl(&e);
+
         if has_administrator(&e) {
             panic!("already initialized")
         }
@@ -71,51 +94,38 @@ impl TokenTrait for Token {
     }
 
     fn allowance(e: Env, from: Address, spender: Address) -> i128 {
-        read_allowance(&e, &from, &spender)
-    }
-
-    fn incr_allow(e: Env, from: Address, spender: Address, amount: i128) {
-        from.require_auth();
-
-        check_nonnegative_amount(amount);
-
-        let allowance = read_allowance(&e, &from, &spender);
-        let new_allowance = allowance
-            .checked_add(amount)
-            .expect("Updated allowance doesn't fit in an i128");
-
-        write_allowance(&e, &from, &spender, new_allowance);
-        event::incr_allow(&e, &from, &spender, amount);
+        extend_instance_ttl(&e);
+        read_allowance(&e, &from, &spender).amount
     }
 
-    fn decr_allow(e: Env, from: Address, spender: Address, amount: i128) {
+    fn approve(e: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        extend_instance_ttl(&e);
         from.require_auth();
 
         check_nonnegative_amount(amount);
 
-        let allowance = read_allowance(&e, &from, &spender);
-        if amount >= allowance {
-            write_allowance(&e, &from, &spender, 0);
-        } else {
-            write_allowance(&e, &from, &spender, allowance - amount);
-        }
-        event::decr_allow(&e, &from, &spender, amount);
+        write_allowance(&e, &from, &spender, amount, expiration_ledger);
+        event::approve(&e, &from, &spender, amount, expiration_ledger);
     }
 
     fn balance(e: Env, id: Address) -> i128 {
+        extend_instance_ttl(&e);
         read_balance(&e, &id)
     }
 
     fn spendable(e: Env, id: Address) -> i128 {
+        extend_instance_ttl(&e);
         read_balance(&e, &id)
     }
 
     fn authorized(e: Env, id: Address) -> bool {
+        extend_instance_ttl(&e);
         is_authorized(&e, &id)
     }
 
     fn transfer(e: Env, from: Address, to: Address, amount: i128) {
-        //from.require_auth();
+        extend_instance_ttl(&e);
+        from.require_auth_for_args((to.clone(), amount).into_val(&e));
 
         check_nonnegative_amount(amount);
         spend_balance(&e, &from, amount);
@@ -124,6 +134,7 @@ impl TokenTrait for Token {
     }
 
     fn transfer_from(e: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        extend_instance_ttl(&e);
         spender.require_auth();
 
         check_nonnegative_amount(amount);
@@ -133,32 +144,73 @@ impl TokenTrait for Token {
         event::transfer(&e, &from, &to, amount)
     }
 
+    fn transfer_and_call(e: Env, from: Address, to: Address, amount: i128, data: Bytes) {
+        extend_instance_ttl(&e);
+        from.require_auth_for_args((to.clone(), amount, data.clone()).into_val(&e));
+
+        check_nonnegative_amount(amount);
+        spend_balance(&e, &from, amount);
+        receive_balance(&e, &to, amount);
+
+        let receiver = TokenReceiverClient::new(&e, &to);
+        let refund = match receiver.try_on_token_received(&from, &amount, &data) {
+            Ok(Ok(refund)) => refund.clamp(0, amount),
+            _ => amount,
+        };
+
+        if refund > 0 {
+            spend_balance(&e, &to, refund);
+            receive_balance(&e, &from, refund);
+        }
+
+        // Only announce the standard `transfer` topic once the receiver's
+        // response is known, and only for what `to` actually ends up holding,
+        // so a rejected or partially-refunded deposit doesn't over-report.
+        let net = amount - refund;
+        if net > 0 {
+            event::transfer(&e, &from, &to, net);
+        }
+
+        if refund == amount {
+            event::transfer_call_refund(&e, &from, &to, amount);
+        } else {
+            event::transfer_call(&e, &from, &to, amount);
+        }
+    }
+
     fn burn(e: Env, from: Address, amount: i128) {
+        extend_instance_ttl(&e);
         from.require_auth();
 
         check_nonnegative_amount(amount);
         spend_balance(&e, &from, amount);
+        decrement_supply(&e, amount);
         event::burn(&e, &from, amount);
     }
 
     fn burn_from(e: Env, spender: Address, from: Address, amount: i128) {
+        extend_instance_ttl(&e);
         spender.require_auth();
 
         check_nonnegative_amount(amount);
         spend_allowance(&e, &from, &spender, amount);
         spend_balance(&e, &from, amount);
+        decrement_supply(&e, amount);
         event::burn(&e, &from, amount)
     }
 
     fn clawback(e: Env, admin: Address, from: Address, amount: i128) {
+        extend_instance_ttl(&e);
         check_nonnegative_amount(amount);
         check_admin(&e, &admin);
         admin.require_auth();
         spend_balance(&e, &from, amount);
+        decrement_supply(&e, amount);
         event::clawback(&e, &admin, &from, amount);
     }
 
     fn set_auth(e: Env, admin: Address, id: Address, authorize: bool) {
+        extend_instance_ttl(&e);
         check_admin(&e, &admin);
         admin.require_auth();
         write_authorization(&e, &id, authorize);
@@ -166,14 +218,17 @@ impl TokenTrait for Token {
     }
 
     fn mint(e: Env, admin: Address, to: Address, amount: i128) {
+        extend_instance_ttl(&e);
         check_nonnegative_amount(amount);
         check_admin(&e, &admin);
         admin.require_auth();
         receive_balance(&e, &to, amount);
+        increment_supply(&e, amount);
         event::mint(&e, &admin, &to, amount);
     }
 
     fn set_admin(e: Env, admin: Address, new_admin: Address) {
+        extend_instance_ttl(&e);
         check_admin(&e, &admin);
         admin.require_auth();
         write_administrator(&e, &new_admin);
@@ -181,14 +236,22 @@ impl TokenTrait for Token {
     }
 
     fn decimals(e: Env) -> u32 {
+        extend_instance_ttl(&e);
         read_decimal(&e)
     }
 
-    fn name(e: Env) -> Bytes {
+    fn name(e: Env) -> String {
+        extend_instance_ttl(&e);
         read_name(&e)
     }
 
-    fn symbol(e: Env) -> Bytes {
+    fn symbol(e: Env) -> String {
+        extend_instance_ttl(&e);
         read_symbol(&e)
     }
+
+    fn total_supply(e: Env) -> i128 {
+        extend_instance_ttl(&e);
+        read_supply(&e)
+    }
 }
@@ -0,0 +1,26 @@
+use crate::token::storage_types::DataKey;
+use soroban_sdk::{Env, String};
+
+pub fn read_decimal(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::Decimal).unwrap()
+}
+
+pub fn write_decimal(e: &Env, d: u8) {
+    e.storage().instance().set(&DataKey::Decimal, &u32::from(d))
+}
+
+pub fn read_name(e: &Env) -> String {
+    e.storage().instance().get(&DataKey::Name).unwrap()
+}
+
+pub fn write_name(e: &Env, name: String) {
+    e.storage().instance().set(&DataKey::Name, &name)
+}
+
+pub fn read_symbol(e: &Env) -> String {
+    e.storage().instance().get(&DataKey::Symbol).unwrap()
+}
+
+pub fn write_symbol(e: &Env, symbol: String) {
+    e.storage().instance().set(&DataKey::Symbol, &symbol)
+}
@@ -0,0 +1,20 @@
+#![cfg(test)]
+//! A minimal `on_token_received` receiver used to exercise
+//! `transfer_and_call`: the first byte of `data` selects accept (0),
+//! reject (1, traps), or partial refund (2, refunds half).
+use crate::token::receiver::TokenReceiverTrait;
+use soroban_sdk::{contract, contractimpl, Address, Bytes, Env};
+
+#[contract]
+pub struct MockReceiver;
+
+#[contractimpl]
+impl TokenReceiverTrait for MockReceiver {
+    fn on_token_received(_e: Env, _from: Address, amount: i128, data: Bytes) -> i128 {
+        match data.get(0) {
+            Some(0) => 0,
+            Some(2) => amount / 2,
+            _ => panic!("receiver rejected the transfer"),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+mod admin;
+mod allowance;
+mod balance;
+pub mod contract;
+mod event;
+mod metadata;
+#[cfg(test)]
+mod mock_receiver;
+mod receiver;
+mod storage_types;
+mod supply;
+#[cfg(test)]
+mod test;
+
+pub use contract::{Token, TokenClient, TokenTrait};
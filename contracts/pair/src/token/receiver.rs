@@ -0,0 +1,16 @@
+//! Interface implemented by contracts that want to receive a deposit and
+//! react to it atomically via [`TokenTrait::transfer_and_call`].
+use soroban_sdk::{contractclient, Address, Bytes, Env};
+
+// Only ever implemented by external receiver contracts (see `mock_receiver`
+// for the test double); nothing in this crate implements it outside tests,
+// so the compiler can't see it's used.
+#[allow(dead_code)]
+#[contractclient(name = "TokenReceiverClient")]
+pub trait TokenReceiverTrait {
+    /// Called after the transferred `amount` has already been credited to
+    /// this contract's balance. Returns the amount that should be refunded
+    /// to `from`; returning `amount` rejects the transfer entirely, and any
+    /// value in between is a partial refund.
+    fn on_token_received(e: Env, from: Address, amount: i128, data: Bytes) -> i128;
+}
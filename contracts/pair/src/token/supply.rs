@@ -0,0 +1,32 @@
+use crate::token::storage_types::DataKey;
+use soroban_sdk::Env;
+
+pub fn read_supply(e: &Env) -> i128 {
+    let key = DataKey::TotalSupply;
+    e.storage()
+        .instance()
+        .get::<DataKey, i128>(&key)
+        .unwrap_or_default()
+}
+
+fn write_supply(e: &Env, supply: i128) {
+    let key = DataKey::TotalSupply;
+    e.storage().instance().set(&key, &supply);
+}
+
+pub fn increment_supply(e: &Env, amount: i128) {
+    let supply = read_supply(e);
+    let new_supply = supply
+        .checked_add(amount)
+        .expect("total supply doesn't fit in an i128");
+    write_supply(e, new_supply);
+}
+
+pub fn decrement_supply(e: &Env, amount: i128) {
+    let supply = read_supply(e);
+    if amount >= supply {
+        write_supply(e, 0);
+    } else {
+        write_supply(e, supply - amount);
+    }
+}
@@ -0,0 +1,234 @@
+#![cfg(test)]
+
+use crate::token::contract::{Token, TokenClient};
+use crate::token::mock_receiver::MockReceiver;
+use crate::token::storage_types::{
+    ALLOWANCE_BUMP_AMOUNT, ALLOWANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT,
+    BALANCE_LIFETIME_THRESHOLD,
+};
+use soroban_sdk::testutils::{Address as _, Ledger, LedgerInfo, MockAuth, MockAuthInvoke};
+use soroban_sdk::{Address, Bytes, Env, IntoVal, String};
+
+fn create_token<'a>(e: &Env, admin: &Address) -> TokenClient<'a> {
+    let contract_address = e.register_contract(None, Token);
+    let token = TokenClient::new(e, &contract_address);
+    token.initialize(
+        admin,
+        &7,
+        &String::from_str(e, "name"),
+        &String::from_str(e, "symbol"),
+    );
+    token
+}
+
+fn advance_ledger(e: &Env, sequence_delta: u32) {
+    e.ledger().set(LedgerInfo {
+        timestamp: e.ledger().timestamp(),
+        protocol_version: e.ledger().protocol_version(),
+        sequence_number: e.ledger().sequence() + sequence_delta,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 16 * 60 * 60 / 5,
+        min_persistent_entry_ttl: BALANCE_LIFETIME_THRESHOLD,
+        max_entry_ttl: BALANCE_BUMP_AMOUNT + 1,
+    });
+}
+
+#[test]
+fn test_balance_ttl_is_extended_on_access() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&admin, &user, &1000);
+
+    advance_ledger(&e, BALANCE_LIFETIME_THRESHOLD - 1);
+    assert_eq!(token.balance(&user), 1000);
+}
+
+#[test]
+fn test_allowance_ttl_is_extended_on_access() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let from = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let expiration_ledger = e.ledger().sequence() + ALLOWANCE_BUMP_AMOUNT;
+    token.approve(&from, &spender, &500, &expiration_ledger);
+
+    advance_ledger(&e, ALLOWANCE_LIFETIME_THRESHOLD - 1);
+    assert_eq!(token.allowance(&from, &spender), 500);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_transfer_requires_from_auth() {
+    let e = Env::default();
+
+    let admin = Address::generate(&e);
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    // Only mock auth for the `mint` call: `mock_all_auths()` would also
+    // auto-authorize the `transfer` below, defeating this test.
+    e.mock_auths(&[MockAuth {
+        address: &admin,
+        invoke: &MockAuthInvoke {
+            contract: &token.address,
+            fn_name: "mint",
+            args: (admin.clone(), from.clone(), 1000_i128).into_val(&e),
+            sub_invokes: &[],
+        },
+    }]);
+    token.mint(&admin, &from, &1000);
+
+    // `from` never authorized this transfer, so it must be rejected even
+    // though the call itself is well-formed.
+    token.transfer(&from, &to, &600);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_transfer_rejects_mismatched_authorized_args() {
+    let e = Env::default();
+
+    let admin = Address::generate(&e);
+    let from = Address::generate(&e);
+    let to = Address::generate(&e);
+    let other = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    e.mock_all_auths();
+    token.mint(&admin, &from, &1000);
+
+    e.mock_auths(&[MockAuth {
+        address: &from,
+        invoke: &MockAuthInvoke {
+            contract: &token.address,
+            fn_name: "transfer",
+            args: (from.clone(), other.clone(), 600_i128).into_val(&e),
+            sub_invokes: &[],
+        },
+    }]);
+
+    // `from` authorized a transfer to `other`, not `to`, so this must fail.
+    token.transfer(&from, &to, &600);
+}
+
+#[test]
+fn test_approve_sets_expiring_allowance() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let from = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let expiration_ledger = e.ledger().sequence() + 1000;
+    token.approve(&from, &spender, &500, &expiration_ledger);
+    assert_eq!(token.allowance(&from, &spender), 500);
+
+    advance_ledger(&e, 1001);
+    assert_eq!(token.allowance(&from, &spender), 0);
+}
+
+#[test]
+fn test_transfer_and_call_delivers_to_accepting_receiver() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let from = Address::generate(&e);
+    let receiver = e.register_contract(None, MockReceiver);
+    let token = create_token(&e, &admin);
+
+    token.mint(&admin, &from, &1000);
+    token.transfer_and_call(&from, &receiver, &400, &Bytes::from_array(&e, &[0]));
+
+    assert_eq!(token.balance(&from), 600);
+    assert_eq!(token.balance(&receiver), 400);
+}
+
+#[test]
+fn test_transfer_and_call_refunds_rejecting_receiver() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let from = Address::generate(&e);
+    let receiver = e.register_contract(None, MockReceiver);
+    let token = create_token(&e, &admin);
+
+    token.mint(&admin, &from, &1000);
+    token.transfer_and_call(&from, &receiver, &400, &Bytes::from_array(&e, &[1]));
+
+    // The receiver trapped, so the whole transfer must be rolled back.
+    assert_eq!(token.balance(&from), 1000);
+    assert_eq!(token.balance(&receiver), 0);
+}
+
+#[test]
+fn test_total_supply_tracks_mint_burn_and_clawback() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    assert_eq!(token.total_supply(), 0);
+
+    token.mint(&admin, &alice, &1000);
+    token.mint(&admin, &bob, &500);
+    assert_eq!(token.total_supply(), 1500);
+
+    token.burn(&alice, &400);
+    assert_eq!(token.total_supply(), 1100);
+
+    token.clawback(&admin, &bob, &500);
+    assert_eq!(token.total_supply(), 600);
+}
+
+#[test]
+#[should_panic(expected = "total supply doesn't fit in an i128")]
+fn test_increment_supply_panics_on_overflow() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&admin, &alice, &i128::MAX);
+
+    // `bob`'s own balance has plenty of room, but the total supply is
+    // already maxed out, so this must panic on the supply overflow check.
+    token.mint(&admin, &bob, &1);
+}
+
+#[test]
+fn test_transfer_and_call_applies_partial_refund() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let from = Address::generate(&e);
+    let receiver = e.register_contract(None, MockReceiver);
+    let token = create_token(&e, &admin);
+
+    token.mint(&admin, &from, &1000);
+    token.transfer_and_call(&from, &receiver, &400, &Bytes::from_array(&e, &[2]));
+
+    assert_eq!(token.balance(&from), 800);
+    assert_eq!(token.balance(&receiver), 200);
+}
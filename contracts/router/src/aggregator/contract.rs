@@ -0,0 +1,97 @@
+//! A stateless router that chains swaps across several liquidity-pair
+//! contracts in a single call, so callers don't need to submit one
+//! transaction per hop (or trust an intermediary to hold funds in between).
+use crate::aggregator::pair_client::PairClient;
+use soroban_sdk::{contract, contractimpl, token, Address, Env, IntoVal, Vec};
+
+pub trait AggregatorTrait {
+    /// Swaps exactly `amount_in` of `path[0]` for `path[path.len() - 1]` by
+    /// routing through `pairs[i]` for each hop `path[i] -> path[i + 1]`.
+    /// Reverts the whole route, including every prior hop's transfer, if any
+    /// hop fails or the final output is below `min_amount_out`.
+    fn swap_exact_in(
+        e: Env,
+        from: Address,
+        path: Vec<Address>,
+        pairs: Vec<Address>,
+        amount_in: i128,
+        min_amount_out: i128,
+    ) -> i128;
+}
+
+/// Constant-product quote with the same 0.3% fee convention as the pairs
+/// this router drives, so the amount it asks a pair to pay out matches what
+/// the pair's own reserves support.
+fn get_amount_out(amount_in: i128, reserve_in: i128, reserve_out: i128) -> i128 {
+    if amount_in <= 0 || reserve_in <= 0 || reserve_out <= 0 {
+        return 0;
+    }
+    let amount_in_with_fee = amount_in * 997;
+    let numerator = amount_in_with_fee * reserve_out;
+    let denominator = reserve_in * 1000 + amount_in_with_fee;
+    numerator / denominator
+}
+
+#[contract]
+pub struct Aggregator;
+
+#[contractimpl]
+impl AggregatorTrait for Aggregator {
+    fn swap_exact_in(
+        e: Env,
+        from: Address,
+        path: Vec<Address>,
+        pairs: Vec<Address>,
+        amount_in: i128,
+        min_amount_out: i128,
+    ) -> i128 {
+        from.require_auth_for_args(
+            (path.clone(), pairs.clone(), amount_in, min_amount_out).into_val(&e),
+        );
+
+        if path.len() < 2 {
+            panic!("path must contain at least one hop");
+        }
+        if pairs.len() != path.len() - 1 {
+            panic!("pairs length must match the number of hops in path");
+        }
+
+        let router = e.current_contract_address();
+        let mut amount = amount_in;
+        let mut sender = from.clone();
+
+        for i in 0..pairs.len() {
+            let token_in = path.get(i).unwrap();
+            let token_out = path.get(i + 1).unwrap();
+            let pair = pairs.get(i).unwrap();
+            let pair_client = PairClient::new(&e, &pair);
+
+            let buy_a = pair_client.token_a() == token_out;
+            let (reserve_a, reserve_b) = pair_client.get_reserves();
+            let (reserve_in, reserve_out) = if buy_a {
+                (reserve_b, reserve_a)
+            } else {
+                (reserve_a, reserve_b)
+            };
+            let out = get_amount_out(amount, reserve_in, reserve_out);
+            if out <= 0 {
+                panic!("insufficient liquidity for hop");
+            }
+
+            token::Client::new(&e, &token_in).transfer(&sender, &pair, &amount);
+
+            let is_last_hop = i == pairs.len() - 1;
+            let recipient = if is_last_hop { from.clone() } else { router.clone() };
+            pair_client.swap(&recipient, &buy_a, &out, &amount);
+
+            amount = out;
+            sender = router.clone();
+        }
+
+        if amount < min_amount_out {
+            panic!("slippage exceeded: output below min_amount_out");
+        }
+
+        amount
+    }
+}
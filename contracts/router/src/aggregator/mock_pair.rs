@@ -0,0 +1,67 @@
+#![cfg(test)]
+//! A minimal constant-product pair used to exercise the router: reserves
+//! are seeded once at `initialize` and updated after each `swap`, and the
+//! payout is a real token transfer out of the pair's own balance.
+use crate::aggregator::pair_client::PairTrait;
+use soroban_sdk::{contract, contracttype, contractimpl, token, Address, Env};
+
+#[derive(Clone)]
+#[contracttype]
+enum DataKey {
+    TokenA,
+    TokenB,
+    ReserveA,
+    ReserveB,
+}
+
+#[contract]
+pub struct MockPair;
+
+#[contractimpl]
+impl MockPair {
+    pub fn initialize(e: Env, token_a: Address, token_b: Address, reserve_a: i128, reserve_b: i128) {
+        e.storage().instance().set(&DataKey::TokenA, &token_a);
+        e.storage().instance().set(&DataKey::TokenB, &token_b);
+        e.storage().instance().set(&DataKey::ReserveA, &reserve_a);
+        e.storage().instance().set(&DataKey::ReserveB, &reserve_b);
+    }
+}
+
+#[contractimpl]
+impl PairTrait for MockPair {
+    fn token_a(e: Env) -> Address {
+        e.storage().instance().get(&DataKey::TokenA).unwrap()
+    }
+
+    fn token_b(e: Env) -> Address {
+        e.storage().instance().get(&DataKey::TokenB).unwrap()
+    }
+
+    fn get_reserves(e: Env) -> (i128, i128) {
+        let reserve_a = e.storage().instance().get(&DataKey::ReserveA).unwrap();
+        let reserve_b = e.storage().instance().get(&DataKey::ReserveB).unwrap();
+        (reserve_a, reserve_b)
+    }
+
+    fn swap(e: Env, to: Address, buy_a: bool, out: i128, in_max: i128) {
+        let token_a: Address = e.storage().instance().get(&DataKey::TokenA).unwrap();
+        let token_b: Address = e.storage().instance().get(&DataKey::TokenB).unwrap();
+        let (reserve_a, reserve_b) = Self::get_reserves(e.clone());
+
+        let pay_token = if buy_a { token_a.clone() } else { token_b.clone() };
+        let reserve_out = if buy_a { reserve_a } else { reserve_b };
+        if out > reserve_out {
+            panic!("not enough liquidity to pay out");
+        }
+
+        token::Client::new(&e, &pay_token).transfer(&e.current_contract_address(), &to, &out);
+
+        let (new_reserve_a, new_reserve_b) = if buy_a {
+            (reserve_a - out, reserve_b + in_max)
+        } else {
+            (reserve_a + in_max, reserve_b - out)
+        };
+        e.storage().instance().set(&DataKey::ReserveA, &new_reserve_a);
+        e.storage().instance().set(&DataKey::ReserveB, &new_reserve_b);
+    }
+}
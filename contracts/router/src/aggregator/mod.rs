@@ -0,0 +1,8 @@
+mod contract;
+#[cfg(test)]
+mod mock_pair;
+mod pair_client;
+#[cfg(test)]
+mod test;
+
+pub use contract::{Aggregator, AggregatorClient, AggregatorTrait};
@@ -0,0 +1,22 @@
+//! Interface implemented by the liquidity-pair contracts a route hops
+//! through, modeled on the constant-product pair's `swap(to, buy_a, out,
+//! in_max)` entry point.
+use soroban_sdk::{contractclient, Address, Env};
+
+// Only ever implemented by external pair contracts (see `mock_pair` for the
+// test double); nothing in this crate implements it outside tests, so the
+// compiler can't see it's used.
+#[allow(dead_code)]
+#[contractclient(name = "PairClient")]
+pub trait PairTrait {
+    fn token_a(e: Env) -> Address;
+
+    fn token_b(e: Env) -> Address;
+
+    fn get_reserves(e: Env) -> (i128, i128);
+
+    /// Sends `out` units of `token_a` (if `buy_a`) or `token_b` (otherwise)
+    /// to `to`, consuming up to `in_max` of the other side, which the caller
+    /// must already have transferred into the pair.
+    fn swap(e: Env, to: Address, buy_a: bool, out: i128, in_max: i128);
+}
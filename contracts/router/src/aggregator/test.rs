@@ -0,0 +1,134 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::aggregator::contract::{Aggregator, AggregatorClient};
+use crate::aggregator::mock_pair::MockPair;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{vec, Address, Env};
+
+fn create_token<'a>(e: &Env, admin: &Address) -> (TokenClient<'a>, StellarAssetClient<'a>) {
+    let sac = e.register_stellar_asset_contract_v2(admin.clone());
+    (
+        TokenClient::new(e, &sac.address()),
+        StellarAssetClient::new(e, &sac.address()),
+    )
+}
+
+fn create_pair<'a>(
+    e: &Env,
+    token_a: &Address,
+    token_b: &Address,
+    reserve_a: i128,
+    reserve_b: i128,
+    token_a_admin: &StellarAssetClient<'a>,
+    token_b_admin: &StellarAssetClient<'a>,
+) -> Address {
+    let pair_address = e.register_contract(None, MockPair);
+    token_a_admin.mint(&pair_address, &reserve_a);
+    token_b_admin.mint(&pair_address, &reserve_b);
+
+    // `initialize` is a plain client call here (not part of `PairTrait`),
+    // so reach it through the generated inherent client.
+    let client = crate::aggregator::mock_pair::MockPairClient::new(e, &pair_address);
+    client.initialize(token_a, token_b, &reserve_a, &reserve_b);
+    pair_address
+}
+
+fn router<'a>(e: &Env) -> AggregatorClient<'a> {
+    let address = e.register_contract(None, Aggregator);
+    AggregatorClient::new(e, &address)
+}
+
+#[test]
+fn test_two_hop_swap_exact_in() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let (token_a, token_a_admin) = create_token(&e, &admin);
+    let (token_b, token_b_admin) = create_token(&e, &admin);
+    let (token_c, token_c_admin) = create_token(&e, &admin);
+
+    let pair_ab = create_pair(
+        &e,
+        &token_a.address,
+        &token_b.address,
+        1_000_000,
+        1_000_000,
+        &token_a_admin,
+        &token_b_admin,
+    );
+    let pair_bc = create_pair(
+        &e,
+        &token_b.address,
+        &token_c.address,
+        1_000_000,
+        1_000_000,
+        &token_b_admin,
+        &token_c_admin,
+    );
+
+    token_a_admin.mint(&user, &1_000);
+
+    let aggregator = router(&e);
+    let path = vec![&e, token_a.address.clone(), token_b.address.clone(), token_c.address.clone()];
+    let pairs = vec![&e, pair_ab, pair_bc];
+
+    let amount_out = aggregator.swap_exact_in(&user, &path, &pairs, &1_000, &1);
+
+    assert_eq!(token_a.balance(&user), 0);
+    assert_eq!(token_c.balance(&user), amount_out);
+    assert!(amount_out > 0 && amount_out < 1_000);
+}
+
+#[test]
+fn test_slippage_protection_reverts_whole_route() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+
+    let (token_a, token_a_admin) = create_token(&e, &admin);
+    let (token_b, token_b_admin) = create_token(&e, &admin);
+    let (token_c, token_c_admin) = create_token(&e, &admin);
+
+    let pair_ab = create_pair(
+        &e,
+        &token_a.address,
+        &token_b.address,
+        1_000_000,
+        1_000_000,
+        &token_a_admin,
+        &token_b_admin,
+    );
+    let pair_bc = create_pair(
+        &e,
+        &token_b.address,
+        &token_c.address,
+        1_000_000,
+        1_000_000,
+        &token_b_admin,
+        &token_c_admin,
+    );
+
+    token_a_admin.mint(&user, &1_000);
+
+    let aggregator = router(&e);
+    let path = vec![&e, token_a.address.clone(), token_b.address.clone(), token_c.address.clone()];
+    let pairs = vec![&e, pair_ab, pair_bc];
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        // No real route can return a billion units of token_c out of 1000 in.
+        aggregator.swap_exact_in(&user, &path, &pairs, &1_000, &1_000_000_000);
+    }));
+
+    assert!(result.is_err());
+    // The whole route, including the first hop's transfer, must be undone.
+    assert_eq!(token_a.balance(&user), 1_000);
+    assert_eq!(token_b.balance(&user), 0);
+    assert_eq!(token_c.balance(&user), 0);
+}